@@ -0,0 +1,44 @@
+//! The bn256/grumpkin cycle.
+//!
+//! bn256 and grumpkin form a 2-cycle of elliptic curves over Ethereum-friendly
+//! fields (the bn256 scalar field is the grumpkin base field and vice versa),
+//! so IVC can be instantiated without forking the crate. Each curve's Nova
+//! trait impls are produced by [`impl_traits!`](crate::impl_traits); both share
+//! the generic MSM code path, routing through [`CpuMsm`](crate::traits::CpuMsm)
+//! unless a vendor MSM provider is wired in via the [`MsmBackend`](crate::traits::MsmBackend) hook.
+use crate::traits::CpuMsm;
+use ff::Field;
+use group::{Curve, Group as _, GroupEncoding};
+use halo2curves::CurveAffine;
+
+pub mod bn256 {
+  pub use halo2curves::bn256::{Fq as Base, Fr as Scalar, G1Affine as Affine, G1 as Point};
+}
+
+pub mod grumpkin {
+  pub use halo2curves::grumpkin::{Fq as Base, Fr as Scalar, G1Affine as Affine, G1 as Point};
+}
+
+// The cycle reuses the crate's Poseidon RO, instantiated over each curve's
+// base field as the existing pasta backend does.
+impl_traits!(
+  bn256,
+  Bn256Compressed,
+  halo2curves::bn256::G1,
+  halo2curves::bn256::G1Affine,
+  "21888242871839275222246405745257275088548364400416034343698204186575808495617",
+  CpuMsm,
+  crate::poseidon::PoseidonRO<bn256::Base, bn256::Scalar>,
+  crate::poseidon::PoseidonROCircuit<bn256::Base>
+);
+
+impl_traits!(
+  grumpkin,
+  GrumpkinCompressed,
+  halo2curves::grumpkin::G1,
+  halo2curves::grumpkin::G1Affine,
+  "21888242871839275222246405745257275088696311157297823662689037894645226208583",
+  CpuMsm,
+  crate::poseidon::PoseidonRO<grumpkin::Base, grumpkin::Scalar>,
+  crate::poseidon::PoseidonROCircuit<grumpkin::Base>
+);
@@ -0,0 +1,150 @@
+//! The `impl_traits!` macro that generates Nova trait impls for a curve module.
+
+/// Generates the `Group` and `CompressedGroup` impls for a curve.
+///
+/// The curve module `$name` must expose `Point` (projective), `Affine`,
+/// `Scalar`, and `Base` types satisfying the `group`/`ff` traits. `$order_str`
+/// is the decimal group order and `$msm` the per-curve
+/// [`MsmBackend`](crate::traits::MsmBackend) (use `crate::traits::CpuMsm` for
+/// the portable default).
+#[macro_export]
+macro_rules! impl_traits {
+  (
+    $name:ident,
+    $name_compressed:ident,
+    $name_curve:path,
+    $name_curve_affine:path,
+    $order_str:literal,
+    $msm:ty,
+    $ro:ty,
+    $ro_circuit:ty
+  ) => {
+    impl $crate::traits::Group for $name::Point {
+      type Base = $name::Base;
+      type Scalar = $name::Scalar;
+      type CompressedGroupElement = $name_compressed;
+      type PreprocessedGroupElement = $name::Affine;
+      type HashFunc = $ro;
+      type HashFuncCircuit = $ro_circuit;
+      type MsmBackend = $msm;
+
+      fn identity() -> Self {
+        <$name::Point as group::Group>::identity()
+      }
+
+      fn generator() -> Self {
+        <$name::Point as group::Group>::generator()
+      }
+
+      fn is_identity(&self) -> subtle::Choice {
+        group::Group::is_identity(self)
+      }
+
+      fn random(rng: impl rand_core::RngCore) -> Self {
+        <$name::Point as group::Group>::random(rng)
+      }
+
+      fn double(&self) -> Self {
+        group::Group::double(self)
+      }
+
+      fn compress(&self) -> Self::CompressedGroupElement {
+        $name_compressed(self.to_bytes())
+      }
+
+      fn preprocessed(&self) -> Self::PreprocessedGroupElement {
+        self.to_affine()
+      }
+
+      fn from_label(label: &'static [u8], n: usize) -> Vec<Self::PreprocessedGroupElement> {
+        <Self as $crate::provider::DlogGroup>::from_label(label, n)
+      }
+
+      fn to_coordinates(&self) -> (Self::Base, Self::Base, bool) {
+        let affine = self.to_affine();
+        let coords = affine.coordinates();
+        if coords.is_none().into() {
+          (Self::Base::zero(), Self::Base::zero(), true)
+        } else {
+          let c = coords.unwrap();
+          (*c.x(), *c.y(), false)
+        }
+      }
+
+      fn hash_to_curve(domain: &'static [u8], msg: &[u8]) -> Self {
+        // Try-and-increment over a Shake256 stream: read `byte_length` bytes,
+        // interpret them as a compressed encoding, and decode once. This cycle
+        // is prime-order, so a successful decode already lies in the
+        // prime-order subgroup and no cofactor clearing is needed.
+        use sha3::digest::{ExtendableOutput, Update, XofReader};
+        let mut counter: u32 = 0;
+        loop {
+          let mut hasher = sha3::Shake256::default();
+          hasher.update(domain);
+          hasher.update(msg);
+          hasher.update(&counter.to_le_bytes());
+          let mut reader = hasher.finalize_xof();
+          let mut repr = <<$name::Point as group::GroupEncoding>::Repr as Default>::default();
+          reader.read(repr.as_mut());
+          if let Some(p) = Option::<Self>::from(
+            <$name::Point as group::GroupEncoding>::from_bytes(&repr),
+          ) {
+            return p;
+          }
+          counter = counter.wrapping_add(1);
+        }
+      }
+
+      fn get_order() -> num_bigint::BigInt {
+        num_bigint::BigInt::parse_bytes($order_str.as_bytes(), 10).unwrap()
+      }
+    }
+
+    impl $crate::provider::DlogGroup for $name::Point {
+      type Affine = $name::Affine;
+      type Compressed = <$name::Point as group::GroupEncoding>::Repr;
+      type ScalarField = $name::Scalar;
+
+      fn compress(&self) -> Self::Compressed {
+        self.to_bytes()
+      }
+
+      fn from_label(label: &'static [u8], n: usize) -> Vec<Self::Affine> {
+        $crate::provider::from_label_generic::<$name::Point>(label, n)
+      }
+    }
+
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub struct $name_compressed(<$name::Point as group::GroupEncoding>::Repr);
+
+    impl $crate::traits::CompressedGroup for $name_compressed {
+      type GroupElement = $name::Point;
+
+      fn from_bytes(bytes: &[u8]) -> subtle::CtOption<Self> {
+        let mut repr = <<$name::Point as group::GroupEncoding>::Repr as Default>::default();
+        if bytes.len() != repr.as_ref().len() {
+          return subtle::CtOption::new($name_compressed(repr), subtle::Choice::from(0u8));
+        }
+        repr.as_mut().copy_from_slice(bytes);
+        // reject malformed encodings in constant time by running the on-curve and
+        // subgroup checks that `decompress` performs
+        let valid = <$name::Point as group::GroupEncoding>::from_bytes(&repr).is_some();
+        subtle::CtOption::new($name_compressed(repr), valid)
+      }
+
+      fn decompress(&self) -> subtle::CtOption<Self::GroupElement> {
+        <$name::Point as group::GroupEncoding>::from_bytes(&self.0)
+      }
+
+      fn as_bytes(&self) -> &[u8] {
+        self.0.as_ref()
+      }
+
+      fn byte_length() -> usize {
+        <<$name::Point as group::GroupEncoding>::Repr as Default>::default()
+          .as_ref()
+          .len()
+      }
+    }
+  };
+}
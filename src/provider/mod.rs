@@ -0,0 +1,16 @@
+//! This module implements the Nova traits for concrete curve backends.
+//!
+//! The shared requirements of a curve are captured by the internal
+//! [`DlogGroup`] trait, expressed in terms of the standard `group`/`ff` crates.
+//! The [`impl_traits!`](crate::impl_traits) macro consumes any curve module that
+//! exposes `Point`/`Affine`/`Scalar`/`Base` and emits the full set of Nova
+//! trait impls, so adding a new (half-)pairing cycle is a single macro
+//! invocation rather than a hand-written file.
+#[macro_use]
+mod macros;
+mod traits;
+
+pub mod bn256_grumpkin;
+
+pub use traits::DlogGroup;
+pub(crate) use traits::from_label_generic;
@@ -0,0 +1,52 @@
+//! Internal traits shared by every curve backend.
+use ff::PrimeField;
+use group::{prime::PrimeCurveAffine, Curve, Group as _};
+use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+use sha3::{
+  digest::{ExtendableOutput, Update, XofReader},
+  Shake256,
+};
+
+/// Derives `n` affine points from a static label by hashing the label together
+/// with the index and mapping onto the curve; shared by every backend.
+pub(crate) fn from_label_generic<G>(label: &'static [u8], n: usize) -> Vec<G::AffineRepr>
+where
+  G: Curve + group::Group,
+{
+  let mut points = Vec::with_capacity(n);
+  for i in 0..n as u32 {
+    let mut hasher = Shake256::default();
+    hasher.update(label);
+    hasher.update(&i.to_le_bytes());
+    let mut reader = hasher.finalize_xof();
+    let mut s = [0u8; 32];
+    reader.read(&mut s);
+    let mut rng = ChaCha20Rng::from_seed(s);
+    points.push(G::random(&mut rng).to_affine());
+  }
+  points
+}
+
+/// The shared surface every curve backend exposes, expressed against the
+/// `group`/`ff` ecosystem so that any curve implementing the standard traits can
+/// be adapted to Nova by the [`impl_traits!`](crate::impl_traits) macro.
+pub trait DlogGroup:
+  Curve<AffineRepr = <Self as DlogGroup>::Affine> + group::Group + group::prime::PrimeCurve
+{
+  /// The affine representation of the curve point
+  type Affine: PrimeCurveAffine<Curve = Self, Scalar = Self::Scalar>
+    + From<Self>
+    + Into<Self>;
+
+  /// A compressed encoding of an affine point
+  type Compressed: Clone + Copy + Send + Sync + AsRef<[u8]>;
+
+  /// The scalar field of the curve
+  type ScalarField: PrimeField;
+
+  /// Compresses this point to the canonical byte encoding of its affine form
+  fn compress(&self) -> Self::Compressed;
+
+  /// Derives a vector of affine points from a static label
+  fn from_label(label: &'static [u8], n: usize) -> Vec<Self::Affine>;
+}
@@ -5,11 +5,14 @@ use bellperson::{
 };
 use core::{
   fmt::Debug,
-  ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign},
+  iter::Sum,
+  ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
 use ff::{PrimeField, PrimeFieldBits};
 use merlin::Transcript;
 use num_bigint::BigInt;
+use rand_core::RngCore;
+use subtle::{Choice, CtOption};
 
 /// Represents an element of a group
 pub trait Group:
@@ -22,6 +25,9 @@ pub trait Group:
   + GroupOpsOwned
   + ScalarMul<<Self as Group>::Scalar>
   + ScalarMulOwned<<Self as Group>::Scalar>
+  + Neg<Output = Self>
+  + Sum
+  + for<'a> Sum<&'a Self>
   + Send
   + Sync
 {
@@ -41,14 +47,44 @@ pub trait Group:
   /// from the base field and squeezes out elements of the scalar field
   type HashFunc: HashFuncTrait<Self::Base, Self::Scalar>;
 
+  /// The backend used to evaluate multiexponentiations; set to [`CpuMsm`] for
+  /// the portable wNAF implementation or to a curve-specific accelerated engine
+  type MsmBackend: MsmBackend<Self>;
+
   /// An alternate implementation of Self::HashFunc in the circuit model
   type HashFuncCircuit: HashFuncCircuitTrait<Self::Base>;
 
-  /// A method to compute a multiexponentation
+  /// Returns the neutral element of the group
+  fn identity() -> Self;
+
+  /// Returns a fixed generator of the prime-order subgroup
+  fn generator() -> Self;
+
+  /// Returns a `Choice` that is set if this element is the neutral element
+  fn is_identity(&self) -> Choice;
+
+  /// Returns a uniformly random element of the group
+  fn random(rng: impl RngCore) -> Self;
+
+  /// Doubles this element
+  #[must_use]
+  fn double(&self) -> Self;
+
+  /// A method to compute a multiexponentation using the configured backend
   fn vartime_multiscalar_mul(
     scalars: &[Self::Scalar],
     bases: &[Self::PreprocessedGroupElement],
-  ) -> Self;
+  ) -> Self {
+    Self::vartime_multiscalar_mul_with::<Self::MsmBackend>(scalars, bases)
+  }
+
+  /// A method to compute a multiexponentation routed through an explicit backend
+  fn vartime_multiscalar_mul_with<B: MsmBackend<Self>>(
+    scalars: &[Self::Scalar],
+    bases: &[Self::PreprocessedGroupElement],
+  ) -> Self {
+    B::msm(scalars, bases)
+  }
 
   /// Compresses the group element
   fn compress(&self) -> Self::CompressedGroupElement;
@@ -64,6 +100,45 @@ pub trait Group:
 
   /// Returns the order of the group as a big integer
   fn get_order() -> BigInt;
+
+  /// Maps a domain-separated byte string to a near-uniform scalar.
+  ///
+  /// The input is expanded with Shake256 to `ceil(log2(order)) + 128` bits and
+  /// reduced modulo the scalar order, bounding the bias by `2^-128`.
+  fn hash_to_scalar(domain: &'static [u8], msg: &[u8]) -> Self::Scalar {
+    use num_bigint::Sign;
+    use sha3::{
+      digest::{ExtendableOutput, Update, XofReader},
+      Shake256,
+    };
+
+    let order = Self::get_order();
+    let nbits = order.bits() as usize + 128;
+    let nbytes = (nbits + 7) / 8;
+
+    let mut hasher = Shake256::default();
+    hasher.update(domain);
+    hasher.update(msg);
+    let mut reader = hasher.finalize_xof();
+    let mut buf = vec![0u8; nbytes];
+    reader.read(&mut buf);
+
+    let reduced = BigInt::from_bytes_le(Sign::Plus, &buf) % &order;
+    let (_, le) = reduced.to_bytes_le();
+    let mut repr = <Self::Scalar as PrimeField>::Repr::default();
+    repr.as_mut()[..le.len()].copy_from_slice(&le);
+    Option::from(Self::Scalar::from_repr(repr)).expect("reduced value is a valid scalar")
+  }
+
+  /// Maps a domain-separated byte string to a curve point.
+  ///
+  /// Implementations use try-and-increment over a Shake256 stream: each attempt
+  /// reads [`CompressedGroup::byte_length`] bytes, interprets them as a
+  /// compressed point encoding, and tries to decode them, incrementing a
+  /// counter on failure. This requires the group to be prime-order, so that a
+  /// successful decode already lands in the prime-order subgroup with no
+  /// separate cofactor clearing.
+  fn hash_to_curve(domain: &'static [u8], msg: &[u8]) -> Self;
 }
 
 /// Represents a compressed version of a group element
@@ -71,11 +146,140 @@ pub trait CompressedGroup: Clone + Copy + Debug + Eq + Sized + Send + Sync + 'st
   /// A type that holds the decompressed version of the compressed group element
   type GroupElement: Group;
 
-  /// Decompresses the compressed group element
-  fn decompress(&self) -> Option<Self::GroupElement>;
+  /// Constructs a compressed element from an untrusted byte slice.
+  ///
+  /// Returns `None` (in constant time with respect to the validity branch) when
+  /// the slice is malformed.
+  fn from_bytes(bytes: &[u8]) -> CtOption<Self>;
+
+  /// Decompresses the compressed group element, performing the on-curve and
+  /// subgroup checks in constant time as `group::GroupEncoding` does.
+  fn decompress(&self) -> CtOption<Self::GroupElement>;
 
   /// Returns a byte array representing the compressed group element
   fn as_bytes(&self) -> &[u8];
+
+  /// Returns the fixed encoded length, in bytes, of a compressed element.
+  ///
+  /// This is the length expected by [`from_bytes`](Self::from_bytes) and read
+  /// per attempt by [`Group::hash_to_curve`].
+  fn byte_length() -> usize;
+}
+
+/// A multiexponentiation backend for a group `G`
+///
+/// Implementations may route to a hardware/SIMD engine; [`CpuMsm`] provides a
+/// correct, portable default built on windowed non-adjacent form.
+pub trait MsmBackend<G: Group> {
+  /// Computes `sum_i scalars[i] * bases[i]`
+  fn msm(scalars: &[G::Scalar], bases: &[G::PreprocessedGroupElement]) -> G;
+}
+
+/// The portable CPU multiexponentiation backend
+#[derive(Clone, Copy, Debug)]
+pub struct CpuMsm;
+
+impl<G: Group> MsmBackend<G> for CpuMsm
+where
+  G::PreprocessedGroupElement: Into<G>,
+{
+  fn msm(scalars: &[G::Scalar], bases: &[G::PreprocessedGroupElement]) -> G {
+    wnaf_multiscalar_mul::<G>(scalars, bases)
+  }
+}
+
+/// Picks the wNAF window width from the number of bases, as `round(ln(n))`
+/// clamped to `2..=12`.
+fn wnaf_window(n: usize) -> usize {
+  if n < 2 {
+    2
+  } else {
+    let w = (n as f64).ln().round() as i64;
+    w.clamp(2, 12) as usize
+  }
+}
+
+/// Decomposes `scalar` into signed width-`w` non-adjacent form digits, least
+/// significant first, by repeatedly peeling the low `w` bits and mapping values
+/// `>= 2^(w-1)` to negative digits with a carry into the next window.
+fn wnaf_digits<F: PrimeField>(scalar: &F, w: usize) -> Vec<i64> {
+  use num_bigint::{BigInt, Sign};
+
+  let base = 1i64 << w;
+  let half = 1i64 << (w - 1);
+  let mut k = BigInt::from_bytes_le(Sign::Plus, scalar.to_repr().as_ref());
+  let mut digits = Vec::new();
+  let zero = BigInt::from(0);
+  let one = BigInt::from(1);
+  while k > zero {
+    if (&k & &one) == one {
+      // the window value is in `0..2^w`, so it fits in the low u64 digit
+      let mut d = (&k % base)
+        .to_u64_digits()
+        .1
+        .first()
+        .copied()
+        .unwrap_or(0) as i64;
+      if d >= half {
+        d -= base;
+      }
+      k -= d;
+      digits.push(d);
+    } else {
+      digits.push(0);
+    }
+    k >>= 1;
+  }
+  digits
+}
+
+/// Generic wNAF multiexponentiation used by [`CpuMsm`].
+fn wnaf_multiscalar_mul<G: Group>(
+  scalars: &[G::Scalar],
+  bases: &[G::PreprocessedGroupElement],
+) -> G
+where
+  G::PreprocessedGroupElement: Into<G>,
+{
+  assert_eq!(scalars.len(), bases.len());
+  let w = wnaf_window(bases.len());
+
+  // For each base `P`, precompute the odd multiples `{1P, 3P, 5P, ...}` up to
+  // `(2^(w-1) - 1)P`, indexed by `(digit - 1) / 2`. There are `2^(w-2)` such
+  // odd multiples, so the largest index actually used is `2^(w-2) - 1`.
+  let table_len = 1usize << (w - 2);
+  let mut tables: Vec<Vec<G>> = Vec::with_capacity(bases.len());
+  let mut all_digits: Vec<Vec<i64>> = Vec::with_capacity(scalars.len());
+  let mut max_len = 0;
+  for (scalar, base) in scalars.iter().zip(bases.iter()) {
+    let p: G = base.clone().into();
+    let two_p = p.double();
+    let mut odds = Vec::with_capacity(table_len);
+    odds.push(p);
+    for i in 1..table_len {
+      odds.push(odds[i - 1] + two_p);
+    }
+    tables.push(odds);
+    let digits = wnaf_digits(scalar, w);
+    max_len = max_len.max(digits.len());
+    all_digits.push(digits);
+  }
+
+  // Horner-style double-and-add across digit positions, from the most to the
+  // least significant, accumulating the bucketed table contributions.
+  let mut acc = G::identity();
+  for pos in (0..max_len).rev() {
+    acc = acc.double();
+    for (digits, table) in all_digits.iter().zip(tables.iter()) {
+      let d = digits.get(pos).copied().unwrap_or(0);
+      if d > 0 {
+        acc += table[((d - 1) / 2) as usize];
+      } else if d < 0 {
+        acc -= table[((-d - 1) / 2) as usize];
+      }
+    }
+  }
+  acc
 }
 
 /// A helper trait to append different types to the transcript
@@ -191,3 +395,116 @@ impl<F: PrimeField> AppendToTranscriptTrait for [F] {
 
 pub mod circuit;
 pub mod snark;
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::provider::bn256_grumpkin::bn256;
+  use ff::Field;
+  use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+
+  type G = bn256::Point;
+
+  /// Straightforward reference multiexponentiation: `sum_i scalars[i] * bases[i]`.
+  fn naive(scalars: &[<G as Group>::Scalar], bases: &[<G as Group>::PreprocessedGroupElement]) -> G {
+    scalars
+      .iter()
+      .zip(bases.iter())
+      .fold(<G as Group>::identity(), |acc, (s, b)| {
+        let p: G = (*b).into();
+        acc + p * *s
+      })
+  }
+
+  /// Builds `n` random bases and scalars (zeroing every `zero_every`-th scalar,
+  /// or none when `zero_every == 0`) and checks the wNAF backend against `naive`.
+  fn check(n: usize, zero_every: usize) {
+    let mut rng = ChaCha20Rng::from_seed([7u8; 32]);
+    let bases: Vec<_> = (0..n)
+      .map(|_| <G as Group>::random(&mut rng).preprocessed())
+      .collect();
+    let scalars: Vec<_> = (0..n)
+      .map(|i| {
+        if zero_every != 0 && i % zero_every == 0 {
+          <G as Group>::Scalar::zero()
+        } else {
+          <G as Group>::Scalar::random(&mut rng)
+        }
+      })
+      .collect();
+    let got = <CpuMsm as MsmBackend<G>>::msm(&scalars, &bases);
+    assert_eq!(got, naive(&scalars, &bases));
+  }
+
+  #[test]
+  fn wnaf_matches_naive() {
+    check(0, 0); // empty input
+    check(1, 0); // single base
+    check(1, 1); // single zero scalar
+    check(5, 0); // small n, window width 2
+    check(30, 7); // large n exercises window width > 2 with some zero scalars
+  }
+
+  #[test]
+  fn hash_to_scalar_is_deterministic_and_reduced() {
+    let a = <G as Group>::hash_to_scalar(b"nova-test", b"hello");
+    let b = <G as Group>::hash_to_scalar(b"nova-test", b"hello");
+    assert_eq!(a, b); // same (domain, msg) yields the same scalar
+    // a distinct message maps elsewhere
+    assert_ne!(a, <G as Group>::hash_to_scalar(b"nova-test", b"world"));
+
+    // the output is a canonical element, hence strictly below the group order
+    use num_bigint::{BigInt, Sign};
+    let order = <G as Group>::get_order();
+    let got = BigInt::from_bytes_le(Sign::Plus, a.to_repr().as_ref());
+    assert!(got < order);
+  }
+
+  #[test]
+  fn hash_to_curve_is_on_curve_and_reproducible() {
+    let p = <G as Group>::hash_to_curve(b"nova-test", b"hello");
+    let q = <G as Group>::hash_to_curve(b"nova-test", b"hello");
+    assert_eq!(p, q); // same (domain, msg) yields the same point
+    assert_ne!(p, <G as Group>::hash_to_curve(b"nova-test", b"world"));
+
+    // a mapped point is a valid encoding: it round-trips through compression
+    let compressed = p.compress();
+    assert_eq!(Option::<G>::from(compressed.decompress()), Some(p));
+  }
+
+  #[test]
+  fn compressed_group_round_trips() {
+    let mut rng = ChaCha20Rng::from_seed([9u8; 32]);
+    let p = <G as Group>::random(&mut rng);
+    let compressed = p.compress();
+    let bytes = compressed.as_bytes().to_vec();
+
+    // compress -> as_bytes -> from_bytes -> decompress recovers the point
+    let parsed = Option::<CompressedGroupElement<G>>::from(
+      <CompressedGroupElement<G> as CompressedGroup>::from_bytes(&bytes),
+    )
+    .expect("a freshly compressed point must parse");
+    assert_eq!(Option::<G>::from(parsed.decompress()), Some(p));
+  }
+
+  #[test]
+  fn from_bytes_rejects_invalid_input() {
+    let len = <CompressedGroupElement<G> as CompressedGroup>::byte_length();
+
+    // a wrong-length slice is rejected
+    let short = vec![0u8; len - 1];
+    assert!(bool::from(
+      <CompressedGroupElement<G> as CompressedGroup>::from_bytes(&short).is_none()
+    ));
+
+    // a correctly sized but non-canonical encoding is rejected
+    let garbage = vec![0xffu8; len];
+    assert!(bool::from(
+      <CompressedGroupElement<G> as CompressedGroup>::from_bytes(&garbage).is_none()
+    ));
+  }
+}
+
+/// Convenience alias for the compressed element type of a group `G`.
+#[cfg(test)]
+type CompressedGroupElement<G> = <G as Group>::CompressedGroupElement;